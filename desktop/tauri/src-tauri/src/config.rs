@@ -0,0 +1,87 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+use crate::supervisor::SupervisorHandle;
+
+/// Engine-facing subset of `config.yaml`. Kept separate from any UI-facing
+/// config view - this is only what the sidecar supervisor needs to push
+/// down to `biodockify-engine` when the file changes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppConfig {
+    #[serde(default = "default_engine_host")]
+    pub engine_host: String,
+    #[serde(default = "default_engine_port")]
+    pub engine_port: u16,
+    #[serde(default = "default_max_concurrent_jobs")]
+    pub max_concurrent_jobs: u32,
+}
+
+fn default_engine_host() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_engine_port() -> u16 {
+    8765
+}
+
+fn default_max_concurrent_jobs() -> u32 {
+    4
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            engine_host: default_engine_host(),
+            engine_port: default_engine_port(),
+            max_concurrent_jobs: default_max_concurrent_jobs(),
+        }
+    }
+}
+
+fn config_file_path() -> Result<PathBuf, String> {
+    tauri::api::path::resolve("../config.yaml", Some(tauri::BaseDirectory::Resource))
+        .map_err(|e| e.to_string())
+}
+
+fn load_from_disk() -> Result<AppConfig, String> {
+    let path = config_file_path()?;
+    if !path.exists() {
+        return Ok(AppConfig::default());
+    }
+    let raw = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    serde_yaml::from_str(&raw).map_err(|e| e.to_string())
+}
+
+/// Watches `config.yaml` for changes, pushes the updated engine parameters
+/// down the supervisor's control channel so the sidecar picks them up live,
+/// and emits `config-changed` to the webview so the dashboard hosted in
+/// this same binary can update its own state without a full app restart.
+pub fn watch(app: AppHandle, supervisor: SupervisorHandle) {
+    tauri::async_runtime::spawn(async move {
+        let Ok(path) = config_file_path() else { return };
+        let mut last_modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+
+        loop {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+
+            let Ok(modified) = std::fs::metadata(&path).and_then(|m| m.modified()) else {
+                continue;
+            };
+            if last_modified == Some(modified) {
+                continue;
+            }
+            last_modified = Some(modified);
+
+            match load_from_disk() {
+                Ok(config) => {
+                    supervisor.request_config_update(config.clone());
+                    let _ = app.emit_all("config-changed", &config);
+                }
+                Err(e) => eprintln!("[BioDockify Host] Failed to reload config.yaml: {}", e),
+            }
+        }
+    });
+}
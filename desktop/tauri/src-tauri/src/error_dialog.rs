@@ -0,0 +1,26 @@
+use tauri::api::dialog::blocking::MessageDialogBuilder;
+use tauri::api::dialog::MessageDialogKind;
+use tauri::{AppHandle, Manager};
+
+/// Shows a native, blocking "something went wrong" dialog describing a
+/// fatal startup failure, so a desktop user sees an explanation instead of
+/// the app silently vanishing. Safe to call even before the main window
+/// exists, since it does not depend on one.
+pub fn show_fatal_error(title: &str, message: &str) {
+    MessageDialogBuilder::new(title, message)
+        .kind(MessageDialogKind::Error)
+        .show();
+}
+
+/// Looks up the main window, logging (rather than panicking) if it is
+/// missing so a transient window-lookup failure never takes down the
+/// tray-resident process.
+pub fn main_window(app: &AppHandle) -> Option<tauri::Window> {
+    match app.get_window("main") {
+        Some(window) => Some(window),
+        None => {
+            eprintln!("[BioDockify Host] Main window not found");
+            None
+        }
+    }
+}
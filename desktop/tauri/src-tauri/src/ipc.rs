@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// A typed message exchanged with the `biodockify-engine` sidecar, framed as
+/// newline-delimited JSON (one `EngineMessage` object per line). Tauri's
+/// sidecar stdout arrives as UTF-8 lines (`CommandEvent::Stdout`, already
+/// split on `\n` with invalid byte sequences lossily replaced), so framing
+/// has to be text-safe rather than a raw byte length prefix.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EngineMessage {
+    LogLine { line: String },
+    JobProgress { id: String, percent: u8 },
+    JobResult { id: String, payload: serde_json::Value },
+    Error { code: String, message: String },
+}
+
+/// Decodes a single line of sidecar stdout into an `EngineMessage`. Falls
+/// back to treating the line as a `LogLine` if it isn't valid JSON, so
+/// plaintext logging from the engine keeps working.
+pub fn decode_line(line: &str) -> EngineMessage {
+    serde_json::from_str(line).unwrap_or_else(|_| EngineMessage::LogLine {
+        line: line.to_string(),
+    })
+}
+
+/// Encodes an outbound message to the sidecar's stdin as a single
+/// newline-terminated JSON line.
+pub fn encode_line(message: &EngineMessage) -> serde_json::Result<String> {
+    Ok(format!("{}\n", serde_json::to_string(message)?))
+}
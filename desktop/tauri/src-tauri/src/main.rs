@@ -1,33 +1,117 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod config;
+mod error_dialog;
+mod ipc;
+mod supervisor;
+mod updater;
+
+#[path = "../../../shared/window_state.rs"]
+mod window_state;
+
+use std::sync::Mutex;
+
 use tauri::{Manager, SystemTray, SystemTrayMenu, SystemTrayMenuItem, SystemTrayEvent, CustomMenuItem};
-use tauri::api::process::{Command, CommandEvent};
+use supervisor::{EngineStatus, SidecarSupervisor, SupervisorHandle};
+use updater::ReleaseManifest;
+
+#[derive(Default)]
+struct PendingUpdate(Mutex<Option<ReleaseManifest>>);
+
+#[tauri::command]
+fn pause_research(handle: tauri::State<SupervisorHandle>) {
+    if handle.status() == EngineStatus::Running {
+        handle.request_pause();
+    }
+}
+
+#[tauri::command]
+fn resume_research(handle: tauri::State<SupervisorHandle>) {
+    if handle.status() == EngineStatus::Paused {
+        handle.request_resume();
+    }
+}
+
+#[tauri::command]
+fn save_window_state(window: tauri::Window) -> Result<(), String> {
+    window_state::save_window_state(&window)
+}
+
+#[tauri::command]
+fn restore_window_state(window: tauri::Window) -> Result<(), String> {
+    window_state::restore_window_state(&window)
+}
+
+#[tauri::command]
+async fn check_for_updates(app: tauri::AppHandle) -> Result<Option<ReleaseManifest>, String> {
+    let manifest = updater::check_for_updates().await?;
+    if let Some(manifest) = &manifest {
+        *app.state::<PendingUpdate>().0.lock().unwrap() = Some(manifest.clone());
+        let _ = app.emit_all("update-available", manifest);
+        let _ = app.tray_handle().get_item("install_update").set_enabled(true);
+    }
+    Ok(manifest)
+}
+
+#[tauri::command]
+async fn install_update(app: tauri::AppHandle) -> Result<(), String> {
+    let manifest = app
+        .state::<PendingUpdate>()
+        .0
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or("no verified update is available")?;
+
+    let supervisor: tauri::State<SupervisorHandle> = app.state();
+    updater::install_update(&manifest, &supervisor).await?;
+
+    tauri::api::process::restart(&app.env());
+    Ok(())
+}
 
 fn main() {
     // Defines the system tray menu
     let quit = CustomMenuItem::new("quit".to_string(), "Quit BioDockify");
     let show = CustomMenuItem::new("show".to_string(), "Show Dashboard");
-    let pause = CustomMenuItem::new("pause".to_string(), "Pause Research").disabled(); // Future implementation
-    
+    let pause = CustomMenuItem::new("pause".to_string(), "Pause Research").disabled();
+    let install_update = CustomMenuItem::new("install_update".to_string(), "Install Update").disabled();
+
     let tray_menu = SystemTrayMenu::new()
         .add_item(show)
         .add_item(pause)
         .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(install_update)
+        .add_native_item(SystemTrayMenuItem::Separator)
         .add_item(quit);
-        
+
     let system_tray = SystemTray::new().with_menu(tray_menu);
 
     tauri::Builder::default()
         .plugin(tauri_plugin_autostart::init(tauri_plugin_autostart::MacosLauncher::LaunchAgent, Some(vec![])))
+        .manage(PendingUpdate::default())
+        .invoke_handler(tauri::generate_handler![
+            pause_research,
+            resume_research,
+            save_window_state,
+            restore_window_state,
+            check_for_updates,
+            install_update,
+        ])
         .system_tray(system_tray)
         .on_system_tray_event(|app, event| match event {
             SystemTrayEvent::LeftClick { .. } => {
-                let window = app.get_window("main").unwrap();
-                if window.is_visible().unwrap() {
-                    window.hide().unwrap();
-                } else {
-                    window.show().unwrap();
-                    window.set_focus().unwrap();
+                let Some(window) = error_dialog::main_window(app) else { return };
+                match window.is_visible() {
+                    Ok(true) => {
+                        let _ = window_state::save_window_state(&window);
+                        let _ = window.hide();
+                    }
+                    Ok(false) => {
+                        let _ = window.show();
+                        let _ = window.set_focus();
+                    }
+                    Err(e) => eprintln!("[BioDockify Host] Failed to read window visibility: {}", e),
                 }
             }
             SystemTrayEvent::MenuItemClick { id, .. } => {
@@ -36,9 +120,33 @@ fn main() {
                         std::process::exit(0);
                     }
                     "show" => {
-                        let window = app.get_window("main").unwrap();
-                        window.show().unwrap();
-                        window.set_focus().unwrap();
+                        if let Some(window) = error_dialog::main_window(app) {
+                            let _ = window.show();
+                            let _ = window.set_focus();
+                        }
+                    }
+                    "pause" | "resume" => {
+                        let handle: tauri::State<SupervisorHandle> = app.state();
+                        let pause_item = app.tray_handle().get_item("pause");
+                        match handle.status() {
+                            EngineStatus::Running => {
+                                handle.request_pause();
+                                let _ = pause_item.set_title("Resume Research");
+                            }
+                            EngineStatus::Paused => {
+                                handle.request_resume();
+                                let _ = pause_item.set_title("Pause Research");
+                            }
+                            _ => {}
+                        }
+                    }
+                    "install_update" => {
+                        let app_handle = app.clone();
+                        tauri::async_runtime::spawn(async move {
+                            if let Err(e) = install_update(app_handle).await {
+                                eprintln!("[BioDockify Host] Failed to install update: {}", e);
+                            }
+                        });
                     }
                     _ => {}
                 }
@@ -47,48 +155,64 @@ fn main() {
         })
         .on_window_event(|event| match event.event() {
             tauri::WindowEvent::CloseRequested { api, .. } => {
-                event.window().hide().unwrap();
+                let _ = window_state::save_window_state(event.window());
+                if let Err(e) = event.window().hide() {
+                    eprintln!("[BioDockify Host] Failed to hide main window: {}", e);
+                }
                 api.prevent_close();
             }
+            tauri::WindowEvent::Moved(_) | tauri::WindowEvent::Resized(_) => {
+                let _ = window_state::save_window_state(event.window());
+            }
             _ => {}
         })
         .setup(|app| {
-            let window = app.get_window("main").unwrap();
-            
-            // Spawn the Agent Zero Backend Sidecar (Auto-Restart Monitor)
+            let app_handle = app.handle();
+
+            if let Some(window) = error_dialog::main_window(&app_handle) {
+                let _ = window_state::restore_window_state(&window);
+            }
+
+            let (supervisor, handle) = SidecarSupervisor::new(app_handle);
+            app.manage(handle.clone());
+            config::watch(app.handle(), handle);
+
+            let tray_handle = app.tray_handle();
+            let status_handle = app.state::<SupervisorHandle>().inner().clone();
+            tauri::async_runtime::spawn(async move {
+                // Enable the tray item once the sidecar actually starts running.
+                while status_handle.status() == EngineStatus::Starting {
+                    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+                }
+                if matches!(status_handle.status(), EngineStatus::Running | EngineStatus::Paused) {
+                    let _ = tray_handle.get_item("pause").set_enabled(true);
+                }
+            });
+
+            // Spawn the Agent Zero Backend Sidecar under a supervisor that
+            // owns restart/backoff policy for the lifetime of the app.
+            tauri::async_runtime::spawn(async move {
+                supervisor.run().await;
+            });
+
+            let updater_handle = app.handle();
             tauri::async_runtime::spawn(async move {
                 loop {
-                    println!("[BioDockify Host] Spawning Backend Sidecar...");
-                    let (mut rx, mut child) = match Command::new_sidecar("biodockify-engine")
-                        .expect("failed to create sidecar configuration")
-                        .spawn() {
-                            Ok(res) => res,
-                            Err(e) => {
-                                eprintln!("[BioDockify Host] Failed to spawn sidecar: {}", e);
-                                std::thread::sleep(std::time::Duration::from_secs(5));
-                                continue;
-                            }
-                        };
-
-                    println!("[BioDockify Host] Backend started. Monitoring...");
-
-                    // Monitor sidecar events
-                    while let Some(event) = rx.recv().await {
-                       if let CommandEvent::Stdout(line) = event {
-                           println!("[AGENT ZERO]: {}", line);
-                       }
-                       // If process exits, the channel might close or sending a specific event?
-                       // CommandEvent doesn't explicitly have "Exit" in simple mode, 
-                       // but rx.recv() returns None when channel closes (process dies).
+                    if let Err(e) = check_for_updates(updater_handle.clone()).await {
+                        eprintln!("[BioDockify Host] Update check failed: {}", e);
                     }
-                    
-                    println!("[BioDockify Host] Sidecar exited unexpectedly. Restarting in 2s...");
-                    std::thread::sleep(std::time::Duration::from_secs(2));
+                    tokio::time::sleep(std::time::Duration::from_secs(6 * 60 * 60)).await;
                 }
             });
-            
+
             Ok(())
         })
         .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .unwrap_or_else(|e| {
+            error_dialog::show_fatal_error(
+                "BioDockify failed to start",
+                &format!("BioDockify could not start: {}\n\nCheck the application log for details.", e),
+            );
+            std::process::exit(1);
+        });
 }
@@ -0,0 +1,296 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use tauri::api::process::{Command, CommandChild, CommandEvent};
+use tauri::{AppHandle, Manager};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::config::AppConfig;
+use crate::ipc;
+
+/// Current lifecycle state of the supervised sidecar, shared with the UI
+/// (tray menu, dashboard) so pause/resume can only be requested while the
+/// engine is actually running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngineStatus {
+    Starting,
+    Running,
+    Paused,
+    Stopped,
+    CrashLooped,
+}
+
+pub enum ControlMessage {
+    Pause,
+    Resume,
+    UpdateConfig(AppConfig),
+    /// Kills the sidecar and acknowledges once it is actually gone, so a
+    /// caller (e.g. the updater) can know it is safe to relaunch.
+    Stop(oneshot::Sender<()>),
+}
+
+/// Cloneable, `Send + Sync` handle that the tray and any Tauri commands can
+/// hold onto (typically via `app.manage(...)`) to query engine status and
+/// request pause/resume without needing a reference to the supervisor task
+/// itself.
+#[derive(Clone)]
+pub struct SupervisorHandle {
+    status: Arc<Mutex<EngineStatus>>,
+    control_tx: mpsc::UnboundedSender<ControlMessage>,
+}
+
+impl SupervisorHandle {
+    pub fn status(&self) -> EngineStatus {
+        *self.status.lock().unwrap()
+    }
+
+    pub fn request_pause(&self) {
+        let _ = self.control_tx.send(ControlMessage::Pause);
+    }
+
+    pub fn request_resume(&self) {
+        let _ = self.control_tx.send(ControlMessage::Resume);
+    }
+
+    pub fn request_config_update(&self, config: AppConfig) {
+        let _ = self.control_tx.send(ControlMessage::UpdateConfig(config));
+    }
+
+    /// Asks the supervisor to kill the sidecar and waits until it confirms
+    /// the process is gone. Used before installing an update so the old
+    /// engine process is never left running (or orphaned) across a relaunch.
+    pub async fn request_stop_and_wait(&self) -> Result<(), String> {
+        let (tx, rx) = oneshot::channel();
+        self.control_tx
+            .send(ControlMessage::Stop(tx))
+            .map_err(|_| "supervisor task is no longer running".to_string())?;
+        rx.await
+            .map_err(|_| "supervisor dropped the stop acknowledgement".to_string())
+    }
+
+    fn set_status(&self, status: EngineStatus) {
+        *self.status.lock().unwrap() = status;
+    }
+}
+
+/// Minimum time a sidecar must stay alive before we consider it "healthy"
+/// and reset the backoff delay back to `BASE_DELAY`.
+const HEALTHY_THRESHOLD: Duration = Duration::from_secs(30);
+const BASE_DELAY: Duration = Duration::from_secs(1);
+const MAX_DELAY: Duration = Duration::from_secs(60);
+/// Number of fast crashes (shorter than `HEALTHY_THRESHOLD`) in a row
+/// before we give up auto-restarting and surface the failure instead.
+const MAX_FAST_CRASHES: u32 = 6;
+
+/// Owns the lifecycle of the `biodockify-engine` sidecar: spawning it,
+/// classifying every `CommandEvent` it emits, and deciding whether/when
+/// to restart it after it exits.
+pub struct SidecarSupervisor {
+    app: AppHandle,
+    child: Option<CommandChild>,
+    backoff: Duration,
+    fast_crash_count: u32,
+    handle: SupervisorHandle,
+    control_rx: mpsc::UnboundedReceiver<ControlMessage>,
+}
+
+/// Applies +/-25% jitter to a backoff delay so a fleet of clients (or a
+/// single client restarting repeatedly) doesn't retry in lockstep.
+fn jittered(backoff: Duration) -> Duration {
+    let jitter_range = backoff.mul_f64(0.25);
+    let offset = rand::thread_rng().gen_range(0..=jitter_range.as_millis() as u64);
+    backoff - jitter_range + Duration::from_millis(offset * 2)
+}
+
+enum RunOutcome {
+    Exited { healthy: bool },
+    SpawnFailed,
+    Stopped,
+}
+
+impl SidecarSupervisor {
+    /// Builds a new supervisor along with the handle that the rest of the
+    /// app (tray, commands) uses to observe status and request pause/resume.
+    pub fn new(app: AppHandle) -> (Self, SupervisorHandle) {
+        let (control_tx, control_rx) = mpsc::unbounded_channel();
+        let handle = SupervisorHandle {
+            status: Arc::new(Mutex::new(EngineStatus::Starting)),
+            control_tx,
+        };
+        let supervisor = Self {
+            app,
+            child: None,
+            backoff: BASE_DELAY,
+            fast_crash_count: 0,
+            handle: handle.clone(),
+            control_rx,
+        };
+        (supervisor, handle)
+    }
+
+    /// Drives the supervise-restart loop until the sidecar is stopped on
+    /// request, or enters a crash-loop state (too many fast crashes in a
+    /// row), in which case it stops restarting, logs the error and shows it
+    /// in a native error dialog.
+    pub async fn run(mut self) {
+        loop {
+            match self.spawn_and_monitor().await {
+                RunOutcome::Stopped => {
+                    // Stopped intentionally (e.g. for an update install) -
+                    // the whole app is expected to relaunch shortly, so
+                    // don't race it by respawning the sidecar again.
+                    return;
+                }
+                RunOutcome::Exited { healthy: true } => {
+                    self.backoff = BASE_DELAY;
+                    self.fast_crash_count = 0;
+                }
+                RunOutcome::Exited { healthy: false } | RunOutcome::SpawnFailed => {
+                    self.fast_crash_count += 1;
+                    if self.fast_crash_count >= MAX_FAST_CRASHES {
+                        let message = format!(
+                            "The BioDockify research engine crashed {} times in a row and has been stopped. Check the application log for details.",
+                            self.fast_crash_count
+                        );
+                        eprintln!("[BioDockify Host] {}", message);
+                        self.handle.set_status(EngineStatus::CrashLooped);
+                        // show_fatal_error blocks on a native dialog; run()
+                        // is driven from an async-spawned task, so bounce it
+                        // onto a blocking thread rather than stalling a
+                        // tokio worker (and, on macOS, the dialog itself).
+                        let _ = tokio::task::spawn_blocking(move || {
+                            crate::error_dialog::show_fatal_error("BioDockify engine stopped", &message);
+                        })
+                        .await;
+                        return;
+                    }
+                    self.backoff = (self.backoff * 2).min(MAX_DELAY);
+                }
+            }
+
+            let delay = jittered(self.backoff);
+            println!(
+                "[BioDockify Host] Restarting sidecar in {:?} (fast crashes: {})",
+                delay, self.fast_crash_count
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Writes a control line to the sidecar's stdin, if it is currently
+    /// running. Used to pause/resume long-running docking jobs without
+    /// tearing the process down.
+    fn write_control_line(&mut self, line: &str) {
+        if let Some(child) = self.child.as_mut() {
+            if let Err(e) = child.write((line.to_string() + "\n").as_bytes()) {
+                eprintln!("[BioDockify Host] Failed to write control message to sidecar: {}", e);
+            }
+        } else {
+            eprintln!("[BioDockify Host] Cannot send control message, sidecar is not running");
+        }
+    }
+
+    fn handle_control_message(&mut self, message: ControlMessage) {
+        match message {
+            ControlMessage::Pause => {
+                self.write_control_line(r#"{"type":"pause"}"#);
+                self.handle.set_status(EngineStatus::Paused);
+            }
+            ControlMessage::Resume => {
+                self.write_control_line(r#"{"type":"resume"}"#);
+                self.handle.set_status(EngineStatus::Running);
+            }
+            ControlMessage::UpdateConfig(config) => {
+                if let Ok(body) = serde_json::to_string(&config) {
+                    self.write_control_line(&format!(r#"{{"type":"update_config","config":{}}}"#, body));
+                }
+            }
+            ControlMessage::Stop(_) => {
+                // Handled directly in spawn_and_monitor, since stopping
+                // needs to end the monitor loop rather than just write a line.
+            }
+        }
+    }
+
+    async fn spawn_and_monitor(&mut self) -> RunOutcome {
+        println!("[BioDockify Host] Spawning Backend Sidecar...");
+        let command = match Command::new_sidecar("biodockify-engine") {
+            Ok(command) => command,
+            Err(e) => {
+                eprintln!("[BioDockify Host] Sidecar binary not found: {}", e);
+                return RunOutcome::SpawnFailed;
+            }
+        };
+        let (mut rx, child) = match command.spawn() {
+            Ok(res) => res,
+            Err(e) => {
+                eprintln!("[BioDockify Host] Failed to spawn sidecar: {}", e);
+                return RunOutcome::SpawnFailed;
+            }
+        };
+        self.child = Some(child);
+        self.handle.set_status(EngineStatus::Running);
+
+        println!("[BioDockify Host] Backend started. Monitoring...");
+        let started_at = Instant::now();
+
+        loop {
+            tokio::select! {
+                event = rx.recv() => {
+                    let Some(event) = event else {
+                        // Channel closed without an explicit Terminated event -
+                        // treat it the same way, since the process is gone either way.
+                        println!("[BioDockify Host] Sidecar channel closed unexpectedly.");
+                        self.child = None;
+                        self.handle.set_status(EngineStatus::Stopped);
+                        let healthy = started_at.elapsed() >= HEALTHY_THRESHOLD;
+                        return RunOutcome::Exited { healthy };
+                    };
+                    match event {
+                        CommandEvent::Stdout(line) => {
+                            // Tauri already delivers stdout as complete, UTF-8
+                            // lines, so each one decodes independently - no
+                            // cross-chunk buffering needed or possible.
+                            let message = ipc::decode_line(&line);
+                            println!("[AGENT ZERO]: {:?}", message);
+                            let _ = self.app.emit_all("engine-message", message);
+                        }
+                        CommandEvent::Stderr(line) => {
+                            eprintln!("[AGENT ZERO][stderr]: {}", line);
+                        }
+                        CommandEvent::Error(err) => {
+                            eprintln!("[AGENT ZERO][error]: {}", err);
+                        }
+                        CommandEvent::Terminated(payload) => {
+                            println!(
+                                "[BioDockify Host] Sidecar terminated (code={:?}, signal={:?})",
+                                payload.code, payload.signal
+                            );
+                            self.child = None;
+                            self.handle.set_status(EngineStatus::Stopped);
+                            let healthy = started_at.elapsed() >= HEALTHY_THRESHOLD;
+                            return RunOutcome::Exited { healthy };
+                        }
+                        _ => {}
+                    }
+                }
+                Some(message) = self.control_rx.recv() => {
+                    match message {
+                        ControlMessage::Stop(ack) => {
+                            if let Some(child) = self.child.take() {
+                                if let Err(e) = child.kill() {
+                                    eprintln!("[BioDockify Host] Failed to kill sidecar: {}", e);
+                                }
+                            }
+                            self.handle.set_status(EngineStatus::Stopped);
+                            let _ = ack.send(());
+                            return RunOutcome::Stopped;
+                        }
+                        other => self.handle_control_message(other),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use tauri::{Manager, PhysicalPosition, PhysicalSize, Window};
+
+const STATE_FILE_NAME: &str = "window-state.json";
+
+/// Saved geometry for the main window. Shared between `desktop/tauri` (the
+/// tray-owning host app) and `ui/desktop` (the dashboard) so the two Tauri
+/// binaries can't drift on the on-disk format the way `ReleaseManifest` did.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowState {
+    pub position: (i32, i32),
+    pub size: (u32, u32),
+    pub maximized: bool,
+    pub fullscreen: bool,
+}
+
+fn state_path(window: &Window) -> Result<PathBuf, String> {
+    let dir = window
+        .app_handle()
+        .path_resolver()
+        .app_config_dir()
+        .ok_or_else(|| "could not resolve app config dir".to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(STATE_FILE_NAME))
+}
+
+/// Captures a window's current geometry to disk. Called on
+/// `Moved`/`Resized` window events and before the window is hidden to tray.
+pub fn save_window_state(window: &Window) -> Result<(), String> {
+    let position = window.outer_position().map_err(|e| e.to_string())?;
+    let size = window.outer_size().map_err(|e| e.to_string())?;
+    let maximized = window.is_maximized().map_err(|e| e.to_string())?;
+    let fullscreen = window.is_fullscreen().map_err(|e| e.to_string())?;
+
+    let state = WindowState {
+        position: (position.x, position.y),
+        size: (size.width, size.height),
+        maximized,
+        fullscreen,
+    };
+
+    let json = serde_json::to_string_pretty(&state).map_err(|e| e.to_string())?;
+    fs::write(state_path(window)?, json).map_err(|e| e.to_string())
+}
+
+/// Restores a window's geometry from disk, if a saved state exists. The
+/// saved position and size are applied only when that rectangle overlaps a
+/// currently connected monitor; the maximized and fullscreen flags are
+/// applied either way. Should be called from `setup()` before the window is
+/// first shown.
+pub fn restore_window_state(window: &Window) -> Result<(), String> {
+    let path = state_path(window)?;
+    if !path.exists() {
+        return Ok(());
+    }
+
+    let json = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    let state: WindowState = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+
+    if is_on_screen(window, state.position, state.size) {
+        let _ = window.set_position(PhysicalPosition::new(state.position.0, state.position.1));
+        let _ = window.set_size(PhysicalSize::new(state.size.0, state.size.1));
+    }
+
+    if state.maximized {
+        let _ = window.maximize();
+    }
+    if state.fullscreen {
+        let _ = window.set_fullscreen(true);
+    }
+
+    Ok(())
+}
+
+/// Guards against restoring a window off-screen when a monitor was
+/// disconnected since the state was last saved.
+fn is_on_screen(window: &Window, position: (i32, i32), size: (u32, u32)) -> bool {
+    let Ok(monitors) = window.available_monitors() else {
+        return false;
+    };
+
+    monitors.iter().any(|monitor| {
+        let m_pos = monitor.position();
+        let m_size = monitor.size();
+        position.0 + (size.0 as i32) > m_pos.x
+            && position.0 < m_pos.x + m_size.width as i32
+            && position.1 + (size.1 as i32) > m_pos.y
+            && position.1 < m_pos.y + m_size.height as i32
+    })
+}
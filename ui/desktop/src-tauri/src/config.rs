@@ -0,0 +1,121 @@
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+/// Strongly typed, validated contents of `config.yaml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppConfig {
+    #[serde(default = "default_engine_host")]
+    pub engine_host: String,
+    #[serde(default = "default_engine_port")]
+    pub engine_port: u16,
+    #[serde(default = "default_max_concurrent_jobs")]
+    pub max_concurrent_jobs: u32,
+    #[serde(default)]
+    pub telemetry_enabled: bool,
+}
+
+fn default_engine_host() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_engine_port() -> u16 {
+    8765
+}
+
+fn default_max_concurrent_jobs() -> u32 {
+    4
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            engine_host: default_engine_host(),
+            engine_port: default_engine_port(),
+            max_concurrent_jobs: default_max_concurrent_jobs(),
+            telemetry_enabled: false,
+        }
+    }
+}
+
+impl AppConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.engine_port == 0 {
+            return Err("engine_port must be non-zero".to_string());
+        }
+        if self.max_concurrent_jobs == 0 {
+            return Err("max_concurrent_jobs must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Managed state wrapping the current config, guarded by an `RwLock` so the
+/// filesystem watcher can update it live while commands read it.
+pub struct ConfigState(pub RwLock<AppConfig>);
+
+fn config_file_path() -> Result<PathBuf, String> {
+    tauri::api::path::resolve("../config.yaml", Some(tauri::BaseDirectory::Resource))
+        .map_err(|e| e.to_string())
+}
+
+fn load_from_disk() -> Result<AppConfig, String> {
+    let path = config_file_path()?;
+    if !path.exists() {
+        return Ok(AppConfig::default());
+    }
+    let raw = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    let config: AppConfig = serde_yaml::from_str(&raw).map_err(|e| e.to_string())?;
+    config.validate()?;
+    Ok(config)
+}
+
+/// Reads `config.yaml` from disk, validates it, and stores it as managed
+/// state. Call once during `setup()`, before anything reads `ConfigState`.
+pub fn init(app: &AppHandle) -> Result<(), String> {
+    let config = load_from_disk()?;
+    app.manage(ConfigState(RwLock::new(config)));
+    Ok(())
+}
+
+/// Re-reads and re-validates `config.yaml`, replacing the managed state and
+/// emitting `config-changed` to the webview so the dashboard can pick up new
+/// parameters without a full app restart. This binary has no sidecar
+/// supervisor, so nothing is sent to the engine from here.
+pub fn reload(app: &AppHandle) -> Result<AppConfig, String> {
+    let config = load_from_disk()?;
+    let state = app.state::<ConfigState>();
+    *state.0.write().unwrap() = config.clone();
+    let _ = app.emit_all("config-changed", &config);
+    Ok(config)
+}
+
+/// Polls `config.yaml`'s modification time once a second and calls
+/// [`reload`] whenever it has changed. Writes that land within the same
+/// one-second poll cause a single reload; writes spread across polls each
+/// cause their own.
+pub fn watch(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let Ok(path) = config_file_path() else { return };
+        let mut last_modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+
+        loop {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+
+            let Ok(modified) = std::fs::metadata(&path).and_then(|m| m.modified()) else {
+                continue;
+            };
+            if last_modified == Some(modified) {
+                continue;
+            }
+            last_modified = Some(modified);
+
+            if let Err(e) = reload(&app) {
+                eprintln!("[BioDockify] Failed to reload config.yaml: {}", e);
+            }
+        }
+    });
+}
@@ -1,8 +1,17 @@
+mod config;
+mod updater;
+
+#[path = "../../../shared/window_state.rs"]
+mod window_state;
+
 use tauri::Manager;
 use tauri::command;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+use config::{AppConfig, ConfigState};
+use updater::{UpdateStatus, UpdaterState};
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GreetingRequest {
     pub name: String,
@@ -62,10 +71,10 @@ fn open_file(path: PathBuf) -> Result<(), String> {
 fn show_in_folder(path: PathBuf) -> Result<(), String> {
     #[cfg(target_os = "windows")]
     let command = "explorer";
-    
+
     #[cfg(not(target_os = "windows"))]
     let command = "open";
-    
+
     if let Some(parent) = path.parent() {
         let folder = parent.to_string_lossy().to_string();
         tauri::api::shell::open(&folder, None::<&str>(None))
@@ -76,38 +85,34 @@ fn show_in_folder(path: PathBuf) -> Result<(), String> {
 }
 
 #[command]
-fn minimize_window() {
+fn minimize_window(_window: tauri::Window) {
     #[cfg(not(target_os = "macos"))]
-    tauri::Manager::app_handle()
-        .get_window("main")
-        .unwrap()
-        .minimize()
-        .expect("Failed to minimize window");
+    if let Err(e) = _window.minimize() {
+        eprintln!("Failed to minimize window: {}", e);
+    }
 }
 
 #[command]
-fn maximize_window() {
+fn maximize_window(_window: tauri::Window) {
     #[cfg(not(target_os = "macos"))]
-    tauri::Manager::app_handle()
-        .get_window("main")
-        .unwrap()
-        .maximize()
-        .expect("Failed to maximize window");
+    if let Err(e) = _window.maximize() {
+        eprintln!("Failed to maximize window: {}", e);
+    }
 }
 
 #[command]
-fn close_window() {
-    tauri::Manager::app_handle()
-        .get_window("main")
-        .unwrap()
-        .close()
-        .expect("Failed to close window");
+fn close_window(window: tauri::Window) {
+    if let Err(e) = window.close() {
+        eprintln!("Failed to close window: {}", e);
+    }
 }
 
 #[command]
 fn toggle_fullscreen(_window: tauri::Window) {
-    _window.set_fullscreen(!_window.is_fullscreen().unwrap_or(false))
-        .expect("Failed to toggle fullscreen");
+    let is_fullscreen = _window.is_fullscreen().unwrap_or(false);
+    if let Err(e) = _window.set_fullscreen(!is_fullscreen) {
+        eprintln!("Failed to toggle fullscreen: {}", e);
+    }
 }
 
 #[command]
@@ -115,6 +120,16 @@ fn is_dev() -> bool {
     cfg!(debug_assertions)
 }
 
+#[command]
+fn save_window_state(window: tauri::Window) -> Result<(), String> {
+    window_state::save_window_state(&window)
+}
+
+#[command]
+fn restore_window_state(window: tauri::Window) -> Result<(), String> {
+    window_state::restore_window_state(&window)
+}
+
 #[command]
 fn get_config_path() -> Result<String, String> {
     tauri::api::path::resolve(
@@ -125,8 +140,46 @@ fn get_config_path() -> Result<String, String> {
     .map_err(|e| e.to_string())
 }
 
+#[command]
+fn get_config(state: tauri::State<ConfigState>) -> AppConfig {
+    state.0.read().unwrap().clone()
+}
+
+#[command]
+fn reload_config(app: tauri::AppHandle) -> Result<AppConfig, String> {
+    config::reload(&app)
+}
+
+#[command]
+async fn check_for_updates(app: tauri::AppHandle) -> Result<UpdateStatus, String> {
+    updater::check_for_updates(&app).await
+}
+
+#[command]
+async fn install_update(app: tauri::AppHandle) -> Result<(), String> {
+    updater::install_update(&app).await
+}
+
 fn lib() {
     tauri::Builder::default()
+        .manage(UpdaterState::default())
+        .setup(|app| {
+            let app_handle = app.handle();
+            config::init(&app_handle)?;
+            config::watch(app_handle.clone());
+
+            let updater_handle = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    if let Err(e) = updater::check_for_updates(&updater_handle).await {
+                        eprintln!("[BioDockify] Update check failed: {}", e);
+                    }
+                    tokio::time::sleep(std::time::Duration::from_secs(6 * 60 * 60)).await;
+                }
+            });
+
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             greet,
             get_app_info,
@@ -139,7 +192,13 @@ fn lib() {
             close_window,
             toggle_fullscreen,
             is_dev,
+            save_window_state,
+            restore_window_state,
             get_config_path,
+            get_config,
+            reload_config,
+            check_for_updates,
+            install_update,
         ])
         .run(tauri::generate_context!())
 }
@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager};
+
+/// base64 ed25519 key set via `BIODOCKIFY_UPDATE_PUBKEY` at build time.
+/// Falls back to an empty key when unset, which fails every verification
+/// rather than silently accepting unsigned updates.
+const UPDATE_PUBLIC_KEY: &str = match option_env!("BIODOCKIFY_UPDATE_PUBKEY") {
+    Some(key) => key,
+    None => "",
+};
+
+const RELEASE_ENDPOINT: &str = "https://updates.biodockify.ai/latest.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseManifest {
+    pub version: String,
+    pub download_url: String,
+    /// Base64-encoded SHA-256 of the bundle `download_url` points at. Part
+    /// of the signed payload, so a CDN/cache serving different bytes at the
+    /// same URL fails verification instead of silently being trusted.
+    pub bundle_hash: String,
+    pub signature: String,
+    pub notes: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateStatus {
+    pub available: bool,
+    pub manifest: Option<ReleaseManifest>,
+}
+
+pub struct UpdaterState(pub RwLock<Option<ReleaseManifest>>);
+
+impl Default for UpdaterState {
+    fn default() -> Self {
+        Self(RwLock::new(None))
+    }
+}
+
+fn is_newer(candidate: &str, current: &str) -> bool {
+    let parse = |v: &str| -> Vec<u32> { v.split('.').filter_map(|p| p.parse().ok()).collect() };
+    parse(candidate) > parse(current)
+}
+
+/// Downloads the signed release manifest, verifies its signature against
+/// the embedded public key, and returns it only if it describes a version
+/// newer than the one currently running.
+pub async fn check_for_updates(app: &AppHandle) -> Result<UpdateStatus, String> {
+    let manifest: ReleaseManifest = reqwest::get(RELEASE_ENDPOINT)
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    verify_signature(&manifest)?;
+
+    let current = env!("CARGO_PKG_VERSION");
+    let available = is_newer(&manifest.version, current);
+
+    let state = app.state::<UpdaterState>();
+    *state.0.write().unwrap() = if available { Some(manifest.clone()) } else { None };
+
+    if available {
+        let _ = app.emit_all("update-available", &manifest);
+    }
+
+    Ok(UpdateStatus {
+        available,
+        manifest: if available { Some(manifest) } else { None },
+    })
+}
+
+fn verify_signature(manifest: &ReleaseManifest) -> Result<(), String> {
+    use ed25519_dalek::{Signature, VerifyingKey, Verifier};
+
+    let key_bytes = base64::decode(UPDATE_PUBLIC_KEY).map_err(|e| e.to_string())?;
+    let verifying_key = VerifyingKey::try_from(key_bytes.as_slice()).map_err(|e| e.to_string())?;
+
+    let signature_bytes = base64::decode(&manifest.signature).map_err(|e| e.to_string())?;
+    let signature = Signature::try_from(signature_bytes.as_slice()).map_err(|e| e.to_string())?;
+
+    let payload = format!("{}:{}:{}", manifest.version, manifest.download_url, manifest.bundle_hash);
+    verifying_key
+        .verify(payload.as_bytes(), &signature)
+        .map_err(|_| "update manifest signature verification failed".to_string())
+}
+
+/// Confirms the downloaded bytes hash to the value covered by the manifest's
+/// signature, so a compromised CDN or MITM serving different bytes at the
+/// same `download_url` is caught before those bytes ever get applied.
+fn verify_bundle_hash(manifest: &ReleaseManifest, bytes: &[u8]) -> Result<(), String> {
+    use sha2::{Digest, Sha256};
+
+    let digest = base64::encode(Sha256::digest(bytes));
+    if digest == manifest.bundle_hash {
+        Ok(())
+    } else {
+        Err("downloaded update bundle does not match the signed hash".to_string())
+    }
+}
+
+/// Stages the downloaded bytes next to the running executable and renames
+/// them over it, so the next `restart()` actually launches the new build
+/// instead of relaunching the binary that was already on disk.
+fn apply_bundle(bytes: &[u8]) -> Result<(), String> {
+    let current_exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let staging_path = current_exe.with_extension("update");
+    std::fs::write(&staging_path, bytes).map_err(|e| e.to_string())?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&staging_path)
+            .map_err(|e| e.to_string())?
+            .permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&staging_path, perms).map_err(|e| e.to_string())?;
+    }
+
+    std::fs::rename(&staging_path, &current_exe).map_err(|e| e.to_string())
+}
+
+/// Downloads and applies the previously-verified update, swapping it in
+/// over the running executable, then relaunches the app. This binary
+/// doesn't own a sidecar process itself, so there is nothing here to pause
+/// or stop before relaunching.
+pub async fn install_update(app: &AppHandle) -> Result<(), String> {
+    let manifest = app
+        .state::<UpdaterState>()
+        .0
+        .read()
+        .unwrap()
+        .clone()
+        .ok_or("no verified update is available")?;
+
+    let bytes = reqwest::get(&manifest.download_url)
+        .await
+        .map_err(|e| e.to_string())?
+        .bytes()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    verify_bundle_hash(&manifest, &bytes)?;
+    apply_bundle(&bytes)?;
+
+    tauri::api::process::restart(&app.env());
+    Ok(())
+}